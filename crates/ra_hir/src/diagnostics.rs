@@ -0,0 +1,160 @@
+//! Diagnostics produced while lowering and type-checking a function body.
+//!
+//! These are HIR-level facts; `ra_ide_api::diagnostics` turns them into
+//! user-facing `Diagnostic`s with concrete source ranges and fixes.
+
+use std::sync::Arc;
+
+use crate::{
+    db::HirDatabase,
+    expr::{Expr, ExprId},
+    name::KnownName,
+    ty::{InferenceResult, Ty},
+    Function, Name, StructField,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FunctionDiagnostic {
+    NoSuchField {
+        expr: ExprId,
+        field: Name,
+    },
+    /// A struct literal doesn't mention one or more of the fields the
+    /// struct declares, and doesn't use `..` to fill them in from a base
+    /// expression either.
+    MissingFields {
+        expr: ExprId,
+        missed_fields: Vec<Name>,
+    },
+    /// The tail expression of a function returning `Result<T, _>` or
+    /// `Option<T>` has type `T` instead of the wrapping type.
+    MissingOkOrSomeInTailExpr {
+        expr: ExprId,
+        required: WrappedType,
+    },
+}
+
+/// Which of `Result`/`Option` a [`FunctionDiagnostic::MissingOkOrSomeInTailExpr`]
+/// expects the tail expression to be wrapped in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WrappedType {
+    Result,
+    Option,
+}
+
+/// Walks a function body and collects the diagnostics above. This is the
+/// single place `Function::diagnostics` delegates to.
+pub(crate) struct ExprValidator<'a, D: HirDatabase> {
+    db: &'a D,
+    func: Function,
+    infer: Arc<InferenceResult>,
+    diagnostics: Vec<FunctionDiagnostic>,
+}
+
+impl<'a, D: HirDatabase> ExprValidator<'a, D> {
+    pub(crate) fn new(db: &'a D, func: Function) -> Self {
+        let infer = func.infer(db);
+        ExprValidator { db, func, infer, diagnostics: Vec::new() }
+    }
+
+    pub(crate) fn validate_body(mut self) -> Vec<FunctionDiagnostic> {
+        let body = self.func.body(self.db);
+        for (id, expr) in body.exprs() {
+            if let Expr::StructLit { fields, spread, .. } = expr {
+                self.validate_struct_lit(id, fields, spread.is_some());
+            }
+        }
+        self.validate_tail_expr(body.body_expr());
+        self.diagnostics
+    }
+
+    fn validate_struct_lit(
+        &mut self,
+        id: ExprId,
+        fields: &[crate::expr::StructLitField],
+        has_spread: bool,
+    ) {
+        // `..base` supplies every field we don't mention explicitly.
+        if has_spread {
+            return;
+        }
+        let struct_field_set: Vec<StructField> = match self.infer.variant_resolution_for_expr(id) {
+            Some(variant) => variant.fields(self.db),
+            None => return,
+        };
+        let present: Vec<Name> = fields.iter().map(|f| f.name.clone()).collect();
+        let missed_fields: Vec<Name> = struct_field_set
+            .into_iter()
+            .map(|field| field.name(self.db))
+            .filter(|name| !present.contains(name))
+            .collect();
+        if !missed_fields.is_empty() {
+            self.diagnostics.push(FunctionDiagnostic::MissingFields { expr: id, missed_fields });
+        }
+    }
+
+    fn validate_tail_expr(&mut self, tail: Option<ExprId>) {
+        let tail = match tail {
+            Some(tail) => tail,
+            None => return,
+        };
+        let body = self.func.body(self.db);
+        if body_contains_return_or_try(&body) {
+            return;
+        }
+
+        let sig = self.func.signature(self.db);
+        let declared_ty = sig.ret_type();
+        let required = match self.wrapped_type_of(declared_ty) {
+            Some(required) => required,
+            None => return,
+        };
+
+        let actual_ty = self.infer.type_of_expr(tail);
+        if self.ty_is_inner_of(&actual_ty, declared_ty) {
+            self.diagnostics.push(FunctionDiagnostic::MissingOkOrSomeInTailExpr { expr: tail, required });
+        }
+    }
+
+    /// If `ty` is the prelude's `core::result::Result<T, E>` or
+    /// `core::option::Option<T>`, returns which of the two it is.
+    ///
+    /// Resolves the known item itself rather than comparing `def`'s name as
+    /// text, so an unrelated enum that happens to be named `Result` or
+    /// `Option` isn't mistaken for the real thing.
+    fn wrapped_type_of(&self, ty: &Ty) -> Option<WrappedType> {
+        let def = match ty {
+            Ty::Adt { def, .. } => *def,
+            _ => return None,
+        };
+        let resolver = self.func.resolver(self.db);
+        if resolver.resolve_known_adt(self.db, KnownName::Result) == Some(def) {
+            Some(WrappedType::Result)
+        } else if resolver.resolve_known_adt(self.db, KnownName::Option) == Some(def) {
+            Some(WrappedType::Option)
+        } else {
+            None
+        }
+    }
+
+    /// Whether `actual` is the inner (`T`) type of `declared`'s
+    /// `Result`/`Option`, i.e. the tail expression needs wrapping in
+    /// `Ok`/`Some` to match.
+    fn ty_is_inner_of(&self, actual: &Ty, declared: &Ty) -> bool {
+        let inner = match declared {
+            Ty::Adt { substs, .. } => match substs.first() {
+                Some(inner) => inner,
+                None => return false,
+            },
+            _ => return false,
+        };
+        actual == inner && actual != declared
+    }
+}
+
+/// A function that uses `return` or `?` anywhere in its body may already
+/// produce the correctly-wrapped value through a different path, so we only
+/// diagnose the simple "bare value as the tail expression" shape.
+fn body_contains_return_or_try(body: &crate::expr::Body) -> bool {
+    body.exprs().any(|(_, expr)| matches!(expr, Expr::Return { .. } | Expr::Try { .. }))
+}
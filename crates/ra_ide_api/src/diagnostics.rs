@@ -3,15 +3,85 @@ use hir::{Problem, source_binder};
 use ra_ide_api_light::Severity;
 use ra_db::SourceDatabase;
 use ra_syntax::{
-    Location, SourceFile, SyntaxKind, TextRange, SyntaxNode,
+    Location, SourceFile, SyntaxKind, TextRange, TextUnit, SyntaxNode,
     ast::{self, AstNode, NameOwner},
 
 };
 use ra_text_edit::{TextEdit, TextEditBuilder};
 
+use std::collections::HashMap;
+
 use crate::{Diagnostic, FileId, FileSystemEdit, SourceChange, SourceFileEdit, db::RootDatabase};
 
-pub(crate) fn diagnostics(db: &RootDatabase, file_id: FileId) -> Vec<Diagnostic> {
+/// A stable identifier for a diagnostic, e.g. `"unnecessary-braces"`.
+///
+/// Unlike `message`, which is free-form text meant for humans, `code` is meant
+/// to be matched on by tooling: editors use it to let users disable a lint or
+/// remap its severity via `DiagnosticsConfig`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiagnosticCode(pub &'static str);
+
+impl DiagnosticCode {
+    pub fn as_str(&self) -> &str {
+        self.0
+    }
+}
+
+const SYNTAX_ERROR: DiagnosticCode = DiagnosticCode("syntax-error");
+const UNNECESSARY_BRACES: DiagnosticCode = DiagnosticCode("unnecessary-braces");
+const STRUCT_SHORTHAND_INITIALIZATION: DiagnosticCode =
+    DiagnosticCode("struct-shorthand-initialization");
+const STRUCT_PATTERN_SHORTHAND: DiagnosticCode = DiagnosticCode("struct-pattern-shorthand");
+const UNRESOLVED_MODULE: DiagnosticCode = DiagnosticCode("unresolved-module");
+const NOT_DIR_OWNER: DiagnosticCode = DiagnosticCode("not-dir-owner");
+const NO_SUCH_FIELD: DiagnosticCode = DiagnosticCode("no-such-field");
+const MISSING_FIELDS: DiagnosticCode = DiagnosticCode("missing-fields");
+const INCORRECT_CASE: DiagnosticCode = DiagnosticCode("incorrect-case");
+const REPLACE_FILTER_MAP_NEXT_WITH_FIND_MAP: DiagnosticCode =
+    DiagnosticCode("replace-filter-map-next-with-find-map");
+const MISSING_OK_OR_SOME_IN_TAIL_EXPR: DiagnosticCode =
+    DiagnosticCode("missing-ok-or-some-in-tail-expr");
+
+/// Lets callers disable individual `DiagnosticCode`s or remap their severity,
+/// e.g. downgrading `unnecessary-braces` to `Severity::Allow` or promoting a
+/// weak warning to a full warning.
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticsConfig {
+    severity_overrides: HashMap<DiagnosticCode, Option<Severity>>,
+}
+
+impl DiagnosticsConfig {
+    pub fn disable(&mut self, code: DiagnosticCode) {
+        self.severity_overrides.insert(code, None);
+    }
+
+    pub fn set_severity(&mut self, code: DiagnosticCode, severity: Severity) {
+        self.severity_overrides.insert(code, Some(severity));
+    }
+
+    fn resolve_severity(&self, code: DiagnosticCode, default: Severity) -> Option<Severity> {
+        match self.severity_overrides.get(&code) {
+            Some(severity_override) => *severity_override,
+            None => Some(default),
+        }
+    }
+}
+
+pub(crate) fn diagnostics(
+    db: &RootDatabase,
+    file_id: FileId,
+    config: &DiagnosticsConfig,
+) -> Vec<Diagnostic> {
+    let mut res = Vec::new();
+    for diag in collect_diagnostics(db, file_id) {
+        if let Some(severity) = config.resolve_severity(diag.code, diag.severity) {
+            res.push(Diagnostic { severity, ..diag });
+        }
+    }
+    res
+}
+
+fn collect_diagnostics(db: &RootDatabase, file_id: FileId) -> Vec<Diagnostic> {
     let source_file = db.parse(file_id);
     let mut res = Vec::new();
 
@@ -20,6 +90,8 @@ pub(crate) fn diagnostics(db: &RootDatabase, file_id: FileId) -> Vec<Diagnostic>
     for node in source_file.syntax().descendants() {
         check_unnecessary_braces_in_use_statement(&mut res, file_id, node);
         check_struct_shorthand_initialization(&mut res, file_id, node);
+        check_struct_shorthand_initialization_pat(&mut res, file_id, node);
+        check_replace_filter_map_next_with_find_map(&mut res, file_id, node);
     }
 
     if let Some(m) = source_binder::module_from_file_id(db, file_id) {
@@ -40,6 +112,7 @@ fn syntax_errors(acc: &mut Vec<Diagnostic>, source_file: &SourceFile) {
         range: location_to_range(err.location()),
         message: format!("Syntax Error: {}", err),
         severity: Severity::Error,
+        code: SYNTAX_ERROR,
         fix: None,
     }));
 }
@@ -66,6 +139,7 @@ fn check_unnecessary_braces_in_use_statement(
             range,
             message: format!("Unnecessary braces in use statement"),
             severity: Severity::WeakWarning,
+            code: UNNECESSARY_BRACES,
             fix: Some(SourceChange {
                 label: "Remove unnecessary braces".to_string(),
                 source_file_edits: vec![SourceFileEdit { file_id, edit }],
@@ -114,6 +188,7 @@ fn check_struct_shorthand_initialization(
                     range: named_field.syntax().range(),
                     message: format!("Shorthand struct initialization"),
                     severity: Severity::WeakWarning,
+                    code: STRUCT_SHORTHAND_INITIALIZATION,
                     fix: Some(SourceChange {
                         label: "use struct shorthand initialization".to_string(),
                         source_file_edits: vec![SourceFileEdit { file_id, edit }],
@@ -127,6 +202,216 @@ fn check_struct_shorthand_initialization(
     Some(())
 }
 
+fn check_struct_shorthand_initialization_pat(
+    acc: &mut Vec<Diagnostic>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let struct_pat = ast::StructPat::cast(node)?;
+    let field_pat_list = struct_pat.field_pat_list()?;
+    for field_pat in field_pat_list.fields() {
+        if let (Some(name), Some(pat)) = (field_pat.name(), field_pat.pat()) {
+            let field_name = name.syntax().text().to_string();
+            let pat_text = pat.syntax().text().to_string();
+            if field_name == pat_text {
+                let mut edit_builder = TextEditBuilder::default();
+                edit_builder.delete(field_pat.syntax().range());
+                edit_builder.insert(field_pat.syntax().range().start(), field_name);
+                let edit = edit_builder.finish();
+
+                acc.push(Diagnostic {
+                    range: field_pat.syntax().range(),
+                    message: format!("Shorthand struct pattern"),
+                    severity: Severity::WeakWarning,
+                    code: STRUCT_PATTERN_SHORTHAND,
+                    fix: Some(SourceChange {
+                        label: "use struct field shorthand".to_string(),
+                        source_file_edits: vec![SourceFileEdit { file_id, edit }],
+                        file_system_edits: Vec::new(),
+                        cursor_position: None,
+                    }),
+                });
+            }
+        }
+    }
+    Some(())
+}
+
+fn check_replace_filter_map_next_with_find_map(
+    acc: &mut Vec<Diagnostic>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let next_call = ast::MethodCallExpr::cast(node)?;
+    if next_call.name_ref()?.syntax().text().to_string() != "next" {
+        return None;
+    }
+    if next_call.arg_list()?.args().next().is_some() {
+        return None;
+    }
+    let filter_map_call = ast::MethodCallExpr::cast(next_call.expr()?.syntax())?;
+    if filter_map_call.name_ref()?.syntax().text().to_string() != "filter_map" {
+        return None;
+    }
+
+    let total_range = next_call.syntax().range();
+    let filter_map_name_range = filter_map_call.name_ref()?.syntax().range();
+    let filter_map_range_end = filter_map_call.syntax().range().end();
+
+    let mut edit_builder = TextEditBuilder::default();
+    edit_builder.replace(filter_map_name_range, "find_map".to_string());
+    edit_builder.delete(TextRange::from_to(filter_map_range_end, total_range.end()));
+    let edit = edit_builder.finish();
+
+    acc.push(Diagnostic {
+        range: total_range,
+        message: "called `filter_map(..).next()` instead of `find_map(..)`".to_string(),
+        severity: Severity::WeakWarning,
+        code: REPLACE_FILTER_MAP_NEXT_WITH_FIND_MAP,
+        fix: Some(SourceChange {
+            label: "replace with find_map".to_string(),
+            source_file_edits: vec![SourceFileEdit { file_id, edit }],
+            file_system_edits: Vec::new(),
+            cursor_position: None,
+        }),
+    });
+    Some(())
+}
+
+#[derive(Clone, Copy)]
+enum CaseType {
+    /// `snake_case`
+    LowerSnakeCase,
+    /// `UpperCamelCase`
+    UpperCamelCase,
+    /// `SCREAMING_SNAKE_CASE`
+    UpperSnakeCase,
+}
+
+impl CaseType {
+    fn convention(self) -> &'static str {
+        match self {
+            CaseType::LowerSnakeCase => "snake_case",
+            CaseType::UpperCamelCase => "UpperCamelCase",
+            CaseType::UpperSnakeCase => "SCREAMING_SNAKE_CASE",
+        }
+    }
+
+    fn suggest(self, ident: &str) -> Option<String> {
+        match self {
+            CaseType::LowerSnakeCase => to_snake_case(ident),
+            CaseType::UpperCamelCase => to_camel_case(ident),
+            CaseType::UpperSnakeCase => to_screaming_snake_case(ident),
+        }
+    }
+}
+
+fn check_name_case<N: NameOwner>(
+    acc: &mut Vec<Diagnostic>,
+    file_id: FileId,
+    node: &N,
+    case: CaseType,
+) -> Option<()> {
+    let name = node.name()?;
+    let ident = name.syntax().text().to_string();
+    let suggestion = case.suggest(&ident)?;
+    let range = name.syntax().range();
+
+    let mut edit_builder = TextEditBuilder::default();
+    edit_builder.replace(range, suggestion.clone());
+    let edit = edit_builder.finish();
+
+    acc.push(Diagnostic {
+        range,
+        message: format!("`{}` should have {} name, e.g. `{}`", ident, case.convention(), suggestion),
+        severity: Severity::WeakWarning,
+        code: INCORRECT_CASE,
+        fix: Some(SourceChange {
+            label: format!(
+                "Rename to {} (note: this may not rename all references)",
+                suggestion
+            ),
+            source_file_edits: vec![SourceFileEdit { file_id, edit }],
+            file_system_edits: Vec::new(),
+            cursor_position: None,
+        }),
+    });
+    Some(())
+}
+
+fn check_function_locals_case(acc: &mut Vec<Diagnostic>, file_id: FileId, fn_def: &ast::FnDef) {
+    let body = match fn_def.body() {
+        Some(body) => body,
+        None => return,
+    };
+    for node in body.syntax().descendants() {
+        if let Some(bind_pat) = ast::BindPat::cast(node) {
+            check_name_case(acc, file_id, bind_pat, CaseType::LowerSnakeCase);
+        }
+    }
+}
+
+/// Converts `ident` to `snake_case`, returning `None` if it is already correct.
+fn to_snake_case(ident: &str) -> Option<String> {
+    let mut res = String::with_capacity(ident.len());
+    let mut prev_is_lower_or_digit = false;
+    for c in ident.chars() {
+        if c.is_uppercase() {
+            if prev_is_lower_or_digit {
+                res.push('_');
+            }
+            res.extend(c.to_lowercase());
+            prev_is_lower_or_digit = false;
+        } else {
+            res.push(c);
+            prev_is_lower_or_digit = c.is_lowercase() || c.is_ascii_digit();
+        }
+    }
+    if res == ident {
+        None
+    } else {
+        Some(res)
+    }
+}
+
+/// Converts `ident` to `SCREAMING_SNAKE_CASE`, returning `None` if it is already correct.
+fn to_screaming_snake_case(ident: &str) -> Option<String> {
+    let snake = to_snake_case(ident).unwrap_or_else(|| ident.to_string());
+    let screaming = snake.to_uppercase();
+    if screaming == ident {
+        None
+    } else {
+        Some(screaming)
+    }
+}
+
+/// Converts `ident` to `UpperCamelCase`, returning `None` if it is already correct.
+///
+/// A run of uppercase letters is treated as an acronym (e.g. `HTTPServer` ->
+/// `HttpServer`), also when it is the whole identifier (`HTTP` -> `Http`).
+fn to_camel_case(ident: &str) -> Option<String> {
+    let mut res = String::with_capacity(ident.len());
+    for chunk in ident.split('_').filter(|chunk| !chunk.is_empty()) {
+        let mut chars = chunk.chars().peekable();
+        let mut is_first = true;
+        while let Some(c) = chars.next() {
+            if is_first {
+                res.extend(c.to_uppercase());
+                is_first = false;
+            } else if c.is_uppercase() && chars.peek().map_or(true, |next| next.is_uppercase()) {
+                res.extend(c.to_lowercase());
+            } else {
+                res.push(c);
+            }
+        }
+    }
+    if res == ident {
+        None
+    } else {
+        Some(res)
+    }
+}
+
 fn check_module(
     acc: &mut Vec<Diagnostic>,
     db: &RootDatabase,
@@ -135,7 +420,45 @@ fn check_module(
 ) {
     for decl in module.declarations(db) {
         match decl {
-            hir::ModuleDef::Function(f) => check_function(acc, db, f),
+            hir::ModuleDef::Function(f) => {
+                check_function(acc, db, file_id, f);
+                let (_file_id, fn_def) = f.source(db);
+                check_name_case(acc, file_id, &fn_def, CaseType::LowerSnakeCase);
+                check_function_locals_case(acc, file_id, &fn_def);
+            }
+            hir::ModuleDef::Struct(s) => {
+                let (_file_id, node) = s.source(db);
+                check_name_case(acc, file_id, &node, CaseType::UpperCamelCase);
+            }
+            hir::ModuleDef::Enum(e) => {
+                let (_file_id, node) = e.source(db);
+                check_name_case(acc, file_id, &node, CaseType::UpperCamelCase);
+                for variant in e.variants(db) {
+                    let (_file_id, node) = variant.source(db);
+                    check_name_case(acc, file_id, &node, CaseType::UpperCamelCase);
+                }
+            }
+            hir::ModuleDef::Trait(t) => {
+                let (_file_id, node) = t.source(db);
+                check_name_case(acc, file_id, &node, CaseType::UpperCamelCase);
+            }
+            hir::ModuleDef::TypeAlias(t) => {
+                let (_file_id, node) = t.source(db);
+                check_name_case(acc, file_id, &node, CaseType::UpperCamelCase);
+            }
+            hir::ModuleDef::Const(c) => {
+                let (_file_id, node) = c.source(db);
+                check_name_case(acc, file_id, &node, CaseType::UpperSnakeCase);
+            }
+            hir::ModuleDef::Static(s) => {
+                let (_file_id, node) = s.source(db);
+                check_name_case(acc, file_id, &node, CaseType::UpperSnakeCase);
+            }
+            hir::ModuleDef::Module(m) => {
+                if let Some((_file_id, node)) = m.declaration_source(db) {
+                    check_name_case(acc, file_id, &node, CaseType::LowerSnakeCase);
+                }
+            }
             _ => (),
         }
     }
@@ -156,6 +479,7 @@ fn check_module(
                     range: name_node.range(),
                     message: "unresolved module".to_string(),
                     severity: Severity::Error,
+                    code: UNRESOLVED_MODULE,
                     fix: Some(fix),
                 }
             }
@@ -177,6 +501,7 @@ fn check_module(
                     range: name_node.range(),
                     message: "can't declare module at this location".to_string(),
                     severity: Severity::Error,
+                    code: NOT_DIR_OWNER,
                     fix: Some(fix),
                 }
             }
@@ -185,7 +510,12 @@ fn check_module(
     }
 }
 
-fn check_function(acc: &mut Vec<Diagnostic>, db: &RootDatabase, function: hir::Function) {
+fn check_function(
+    acc: &mut Vec<Diagnostic>,
+    db: &RootDatabase,
+    file_id: FileId,
+    function: hir::Function,
+) {
     let (_file_id, fn_def) = function.source(db);
     let source_file = fn_def.syntax().ancestors().find_map(ast::SourceFile::cast).unwrap();
     let source_map = function.body_source_map(db);
@@ -198,14 +528,128 @@ fn check_function(acc: &mut Vec<Diagnostic>, db: &RootDatabase, function: hir::F
                         message: "no such field".into(),
                         range: field.syntax().range(),
                         severity: Severity::Error,
+                        code: NO_SUCH_FIELD,
                         fix: None,
                     })
                 }
             }
+            hir::diagnostics::FunctionDiagnostic::MissingFields { expr, missed_fields } => {
+                if let Some(expr) = source_map.expr_syntax(expr) {
+                    let expr = expr.to_node(&source_file);
+                    if let Some(struct_lit) = ast::StructLit::cast(expr.syntax()) {
+                        if let Some(named_field_list) = struct_lit.named_field_list() {
+                            let (field_indent, brace_indent) =
+                                field_list_indent(&source_file, &struct_lit, &named_field_list);
+                            let insert_offset =
+                                named_field_list.syntax().range().end() - TextUnit::of_char('}');
+                            let existing_fields_end = match named_field_list.fields().last() {
+                                Some(last_field) => last_field.syntax().range().end(),
+                                None => {
+                                    named_field_list.syntax().range().start()
+                                        + TextUnit::of_char('{')
+                                }
+                            };
+
+                            let mut missing_fields_text = String::new();
+                            if named_field_list.fields().next().is_some() {
+                                missing_fields_text.push(',');
+                            }
+                            for field in missed_fields.iter() {
+                                missing_fields_text
+                                    .push_str(&format!("\n{}{}: todo!(),", field_indent, field));
+                            }
+                            missing_fields_text.push_str(&format!("\n{}", brace_indent));
+
+                            let mut edit_builder = TextEditBuilder::default();
+                            edit_builder.delete(TextRange::from_to(existing_fields_end, insert_offset));
+                            edit_builder.insert(existing_fields_end, missing_fields_text);
+                            let edit = edit_builder.finish();
+
+                            acc.push(Diagnostic {
+                                message: "Missing structure fields".into(),
+                                range: struct_lit.syntax().range(),
+                                severity: Severity::Error,
+                                code: MISSING_FIELDS,
+                                fix: Some(SourceChange {
+                                    label: "fill struct fields".to_string(),
+                                    source_file_edits: vec![SourceFileEdit { file_id, edit }],
+                                    file_system_edits: Vec::new(),
+                                    cursor_position: None,
+                                }),
+                            })
+                        }
+                    }
+                }
+            }
+            hir::diagnostics::FunctionDiagnostic::MissingOkOrSomeInTailExpr { expr, required } => {
+                if let Some(expr) = source_map.expr_syntax(expr) {
+                    let expr = expr.to_node(&source_file);
+                    let wrapper = match required {
+                        hir::diagnostics::WrappedType::Result => "Ok",
+                        hir::diagnostics::WrappedType::Option => "Some",
+                    };
+                    let range = expr.syntax().range();
+
+                    let mut edit_builder = TextEditBuilder::default();
+                    edit_builder.insert(range.start(), format!("{}(", wrapper));
+                    edit_builder.insert(range.end(), ")".to_string());
+                    let edit = edit_builder.finish();
+
+                    acc.push(Diagnostic {
+                        range,
+                        message: format!(
+                            "expected {}, found the wrapped type",
+                            if wrapper == "Ok" { "Result" } else { "Option" }
+                        ),
+                        severity: Severity::Error,
+                        code: MISSING_OK_OR_SOME_IN_TAIL_EXPR,
+                        fix: Some(SourceChange {
+                            label: format!("wrap return expression in {}", wrapper),
+                            source_file_edits: vec![SourceFileEdit { file_id, edit }],
+                            file_system_edits: Vec::new(),
+                            cursor_position: None,
+                        }),
+                    })
+                }
+            }
         }
     }
 }
 
+/// Guesses the indentation to use for a missing field we're about to insert
+/// into `field_list`, and for the field list's closing brace, so the result
+/// lines up whether or not the literal already spans multiple lines.
+///
+/// Returns `(field_indent, brace_indent)`. If fields are already on their
+/// own lines, both are taken from that existing layout. Otherwise the
+/// literal sits on a single line and we derive the indentation from its own
+/// position in `source_file`, indenting new fields one level deeper than the
+/// line it starts on.
+fn field_list_indent(
+    source_file: &SourceFile,
+    struct_lit: &ast::StructLit,
+    field_list: &ast::NamedFieldList,
+) -> (String, String) {
+    let text = field_list.syntax().text().to_string();
+    let mut lines = text.lines();
+    let field_indent = lines.nth(1).map(leading_whitespace);
+    let brace_indent = lines.last().map(leading_whitespace);
+    if let (Some(field_indent), Some(brace_indent)) = (field_indent, brace_indent) {
+        return (field_indent, brace_indent);
+    }
+
+    let whole_file = source_file.syntax().text().to_string();
+    let start: usize = u32::from(struct_lit.syntax().range().start()) as usize;
+    let line_start = whole_file[..start].rfind('\n').map_or(0, |i| i + 1);
+    let brace_indent = leading_whitespace(&whole_file[line_start..start]);
+    let field_indent = format!("{}    ", brace_indent);
+    (field_indent, brace_indent)
+}
+
+fn leading_whitespace(line: &str) -> String {
+    line.chars().take_while(|c| c.is_whitespace()).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use test_utils::assert_eq_text;
@@ -237,6 +681,44 @@ mod tests {
         assert_eq_text!(after, &actual);
     }
 
+    fn check_no_diagnostic(content: &str) {
+        let (analysis, file_id) = crate::mock_analysis::single_file(content);
+        let diagnostics = analysis.diagnostics(file_id).unwrap();
+        assert_eq!(diagnostics.len(), 0, "expected no diagnostics, found: {:?}", diagnostics);
+    }
+
+    fn check_fix(before: &str, after: &str) {
+        let (analysis, file_id) = crate::mock_analysis::single_file(before);
+        let diagnostic = analysis
+            .diagnostics(file_id)
+            .unwrap()
+            .pop()
+            .unwrap_or_else(|| panic!("no diagnostics for:\n{}\n", before));
+        let mut fix = diagnostic.fix.unwrap();
+        let edit = fix.source_file_edits.pop().unwrap().edit;
+        let actual = edit.apply(before);
+        assert_eq_text!(after, &actual);
+    }
+
+    #[test]
+    fn test_diagnostics_config_disable() {
+        let (analysis, file_id) = crate::mock_analysis::single_file("use {b};");
+        let mut config = DiagnosticsConfig::default();
+        config.disable(UNNECESSARY_BRACES);
+        let diagnostics = analysis.diagnostics_with_config(file_id, &config).unwrap();
+        assert!(diagnostics.is_empty(), "expected no diagnostics, found: {:?}", diagnostics);
+    }
+
+    #[test]
+    fn test_diagnostics_config_set_severity() {
+        let (analysis, file_id) = crate::mock_analysis::single_file("use {b};");
+        let mut config = DiagnosticsConfig::default();
+        config.set_severity(UNNECESSARY_BRACES, Severity::Error);
+        let diagnostics = analysis.diagnostics_with_config(file_id, &config).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+    }
+
     #[test]
     fn test_check_unnecessary_braces_in_use_statement() {
         check_not_applicable(
@@ -335,4 +817,292 @@ fn main() {
             check_struct_shorthand_initialization,
         );
     }
+
+    #[test]
+    fn test_check_struct_shorthand_initialization_pat() {
+        check_not_applicable(
+            r#"
+            struct A {
+                a: &'static str
+            }
+
+            fn main() {
+                let A { a: hello } = A { a: "hello" };
+            }
+        "#,
+            check_struct_shorthand_initialization_pat,
+        );
+
+        check_apply(
+            r#"
+struct A {
+    a: &'static str
+}
+
+fn main() {
+    let A { a: a } = A { a: "haha" };
+}
+        "#,
+            r#"
+struct A {
+    a: &'static str
+}
+
+fn main() {
+    let A { a } = A { a: "haha" };
+}
+        "#,
+            check_struct_shorthand_initialization_pat,
+        );
+
+        check_apply(
+            r#"
+struct A {
+    a: &'static str,
+    b: &'static str
+}
+
+fn main() {
+    let A { a: a, b } = A { a: "haha", b: "bb" };
+}
+        "#,
+            r#"
+struct A {
+    a: &'static str,
+    b: &'static str
+}
+
+fn main() {
+    let A { a, b } = A { a: "haha", b: "bb" };
+}
+        "#,
+            check_struct_shorthand_initialization_pat,
+        );
+    }
+
+    #[test]
+    fn test_check_missing_fields_no_diagnostic() {
+        check_no_diagnostic(
+            r#"
+struct A {
+    a: &'static str,
+    b: &'static str,
+}
+
+fn main() {
+    A { a: "a", b: "b" };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_check_missing_fields_no_diagnostic_with_rest() {
+        check_no_diagnostic(
+            r#"
+struct A {
+    a: &'static str,
+    b: &'static str,
+}
+
+fn main() {
+    let a = A { a: "a", b: "b" };
+    let b = A { a: "aa", ..a };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_check_replace_filter_map_next_with_find_map() {
+        check_not_applicable(
+            r#"
+            fn foo() {
+                let m = bar.filter_map(|x| Some(x));
+            }
+        "#,
+            check_replace_filter_map_next_with_find_map,
+        );
+        check_not_applicable(
+            r#"
+            fn foo() {
+                let m = bar.next();
+            }
+        "#,
+            check_replace_filter_map_next_with_find_map,
+        );
+
+        check_apply(
+            "fn foo() { let m = bar.filter_map(|x| Some(x)).next(); }",
+            "fn foo() { let m = bar.find_map(|x| Some(x)); }",
+            check_replace_filter_map_next_with_find_map,
+        );
+    }
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("fooBar"), Some("foo_bar".to_string()));
+        assert_eq!(to_snake_case("foo_bar"), None);
+    }
+
+    #[test]
+    fn test_to_screaming_snake_case() {
+        assert_eq!(to_screaming_snake_case("fooBar"), Some("FOO_BAR".to_string()));
+        assert_eq!(to_screaming_snake_case("FOO_BAR"), None);
+    }
+
+    #[test]
+    fn test_to_camel_case() {
+        assert_eq!(to_camel_case("foo_bar"), Some("FooBar".to_string()));
+        assert_eq!(to_camel_case("FooBar"), None);
+        assert_eq!(to_camel_case("HTTPServer"), Some("HttpServer".to_string()));
+        assert_eq!(to_camel_case("HttpServer"), None);
+    }
+
+    #[test]
+    fn test_incorrect_case_function_name() {
+        check_fix(r#"fn fooBar() {}"#, r#"fn foo_bar() {}"#);
+    }
+
+    #[test]
+    fn test_incorrect_case_struct_name() {
+        check_fix(r#"struct foo_bar;"#, r#"struct FooBar;"#);
+    }
+
+    #[test]
+    fn test_incorrect_case_const_name() {
+        check_fix(r#"const fooBar: u32 = 1;"#, r#"const FOO_BAR: u32 = 1;"#);
+    }
+
+    #[test]
+    fn test_incorrect_case_local_name() {
+        check_fix(r#"fn main() { let fooBar = 92; }"#, r#"fn main() { let foo_bar = 92; }"#);
+    }
+
+    #[test]
+    fn test_incorrect_case_module_name() {
+        check_fix(r#"mod fooBar { }"#, r#"mod foo_bar { }"#);
+    }
+
+    #[test]
+    fn test_incorrect_case_trait_name() {
+        check_fix(r#"trait foo_bar { }"#, r#"trait FooBar { }"#);
+    }
+
+    #[test]
+    fn test_incorrect_case_enum_name() {
+        check_fix(r#"enum foo_bar { }"#, r#"enum FooBar { }"#);
+    }
+
+    #[test]
+    fn test_incorrect_case_enum_variant_name() {
+        check_fix(r#"enum FooBar { baz_qux }"#, r#"enum FooBar { BazQux }"#);
+    }
+
+    #[test]
+    fn test_incorrect_case_static_name() {
+        check_fix(r#"static fooBar: u32 = 1;"#, r#"static FOO_BAR: u32 = 1;"#);
+    }
+
+    #[test]
+    fn test_wrap_tail_expr_in_ok_not_applicable() {
+        check_no_diagnostic(
+            r#"
+fn div(x: i32, y: i32) -> Result<i32, ()> {
+    if y == 0 {
+        return Err(());
+    }
+    Ok(x / y)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_wrap_tail_expr_in_ok() {
+        check_fix(
+            r#"
+fn div(x: i32, y: i32) -> Result<i32, ()> {
+    x / y
+}
+"#,
+            r#"
+fn div(x: i32, y: i32) -> Result<i32, ()> {
+    Ok(x / y)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_wrap_tail_expr_in_some() {
+        check_fix(
+            r#"
+fn div(x: i32, y: i32) -> Option<i32> {
+    x / y
+}
+"#,
+            r#"
+fn div(x: i32, y: i32) -> Option<i32> {
+    Some(x / y)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_check_missing_fields_one_missing() {
+        check_fix(
+            r#"
+struct A {
+    a: &'static str,
+    b: &'static str,
+}
+
+fn main() {
+    let a = A { a: "a" };
+}
+"#,
+            r#"
+struct A {
+    a: &'static str,
+    b: &'static str,
+}
+
+fn main() {
+    let a = A { a: "a",
+        b: todo!(),
+    };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_check_missing_fields_one_missing_trailing_comma() {
+        check_fix(
+            r#"
+struct A {
+    a: &'static str,
+    b: &'static str,
+}
+
+fn main() {
+    let a = A { a: "a", };
+}
+"#,
+            r#"
+struct A {
+    a: &'static str,
+    b: &'static str,
+}
+
+fn main() {
+    let a = A { a: "a",
+        b: todo!(),
+    };
+}
+"#,
+        );
+    }
 }
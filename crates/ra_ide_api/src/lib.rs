@@ -0,0 +1,86 @@
+//! ra_ide_api crate provides "ide-centric" APIs for the rust-analyzer. That
+//! is, it generally operates with files and text ranges, and returns
+//! results as Strings, suitable for displaying to the human.
+//!
+//! What powers this API? A `RootDatabase`, which is a salsa database
+//! hooked up to all the other crates in this repository.
+
+mod db;
+mod mock_analysis;
+mod diagnostics;
+
+use ra_db::SourceDatabase;
+use ra_syntax::{SourceFile, TextRange};
+use relative_path::RelativePathBuf;
+
+pub use crate::diagnostics::{DiagnosticCode, DiagnosticsConfig};
+pub use ra_db::{Canceled, FileId, FilePosition, SourceRootId};
+pub use ra_ide_api_light::Severity;
+
+pub type Cancelable<T> = Result<T, Canceled>;
+
+/// A fix or refactor a user can apply to their code, described as a set of
+/// concrete edits to files (and, occasionally, the file system itself).
+#[derive(Debug)]
+pub struct SourceChange {
+    pub label: String,
+    pub source_file_edits: Vec<SourceFileEdit>,
+    pub file_system_edits: Vec<FileSystemEdit>,
+    pub cursor_position: Option<FilePosition>,
+}
+
+#[derive(Debug)]
+pub struct SourceFileEdit {
+    pub file_id: FileId,
+    pub edit: ra_text_edit::TextEdit,
+}
+
+#[derive(Debug)]
+pub enum FileSystemEdit {
+    CreateFile { source_root: SourceRootId, path: RelativePathBuf },
+    MoveFile { src: FileId, dst_source_root: SourceRootId, dst_path: RelativePathBuf },
+}
+
+/// A single diagnostic reported for a file: a human-readable `message`, a
+/// stable `code` tooling can match on, and an optional `fix` the user can
+/// apply to resolve it.
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub message: String,
+    pub range: TextRange,
+    pub severity: Severity,
+    pub code: DiagnosticCode,
+    pub fix: Option<SourceChange>,
+}
+
+/// Analysis is the main entry point into rust-analyzer. It is created once
+/// for a snapshot of the world and is immutable.
+pub struct Analysis {
+    db: db::RootDatabase,
+}
+
+impl Analysis {
+    pub fn parse(&self, file_id: FileId) -> Cancelable<SourceFile> {
+        self.with_db(|db| db.parse(file_id))
+    }
+
+    /// Computes the set of diagnostics for `file_id`, applying `config`'s
+    /// per-code severity overrides.
+    pub fn diagnostics_with_config(
+        &self,
+        file_id: FileId,
+        config: &DiagnosticsConfig,
+    ) -> Cancelable<Vec<Diagnostic>> {
+        self.with_db(|db| diagnostics::diagnostics(db, file_id, config))
+    }
+
+    /// Computes the set of diagnostics for `file_id` with the default
+    /// config, i.e. no codes disabled or remapped.
+    pub fn diagnostics(&self, file_id: FileId) -> Cancelable<Vec<Diagnostic>> {
+        self.diagnostics_with_config(file_id, &DiagnosticsConfig::default())
+    }
+
+    fn with_db<F: FnOnce(&db::RootDatabase) -> T, T>(&self, f: F) -> Cancelable<T> {
+        self.db.catch_canceled(f)
+    }
+}